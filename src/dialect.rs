@@ -0,0 +1,259 @@
+//! Pluggable SQL dialects.
+//!
+//! The pest grammar in `sql.pest` defines SELECT's structural syntax once;
+//! a [`Dialect`] customizes only the lexical details that vary across SQL
+//! engines — which quote characters wrap an identifier, and which extra
+//! words are reserved. [`normalize`] strips a dialect's supported quoting
+//! before the input reaches the grammar, so the same `Rule::sql` parses
+//! MySQL's `` `orders` `` and Postgres's `"orders"` alike, as long as the
+//! quoted text is itself a legal bare `identifier` — the grammar's own
+//! `(ASCII_ALPHA | "_")` start class is not dialect-dependent, so a quoted
+//! identifier starting with a digit still fails to parse under every
+//! dialect; [`validate_identifiers`] then rejects any identifier the
+//! dialect reserves. Quoting a reserved word or a name with
+//! spaces/punctuation is not yet supported — that would need the grammar
+//! itself to grow a quoted-identifier rule, not just lexical stripping.
+
+use crate::{SelectItem, SelectQuery, Table, WhereExpr};
+
+/// Lexical rules a specific SQL engine applies on top of the shared
+/// structural grammar.
+pub trait Dialect {
+    /// Whether this dialect accepts backtick-quoted identifiers (MySQL).
+    fn supports_backtick_quotes(&self) -> bool {
+        false
+    }
+
+    /// Whether this dialect accepts double-quoted identifiers (ANSI/Postgres).
+    fn supports_double_quoted_identifiers(&self) -> bool {
+        false
+    }
+
+    /// Whether this dialect accepts bracket-quoted identifiers (T-SQL-style).
+    fn supports_bracket_quotes(&self) -> bool {
+        false
+    }
+
+    /// Whether `word` is reserved in this dialect and must not be used as a
+    /// bare identifier, alias, or table name, beyond the grammar's own
+    /// keyword set.
+    fn is_reserved_word(&self, word: &str) -> bool {
+        let _ = word;
+        false
+    }
+}
+
+/// The crate's original grammar as-is: no quoted identifiers, no reserved
+/// words beyond the grammar's own keyword set.
+#[derive(Debug, Default)]
+pub struct GenericDialect;
+
+impl Dialect for GenericDialect {}
+
+/// ANSI SQL: double-quoted identifiers.
+#[derive(Debug, Default)]
+pub struct AnsiDialect;
+
+impl Dialect for AnsiDialect {
+    fn supports_double_quoted_identifiers(&self) -> bool {
+        true
+    }
+}
+
+/// MySQL: backtick-quoted identifiers.
+#[derive(Debug, Default)]
+pub struct MySqlDialect;
+
+impl Dialect for MySqlDialect {
+    fn supports_backtick_quotes(&self) -> bool {
+        true
+    }
+}
+
+/// PostgreSQL: double-quoted identifiers, plus a handful of words Postgres
+/// reserves that this crate's grammar does not.
+#[derive(Debug, Default)]
+pub struct PostgresDialect;
+
+impl Dialect for PostgresDialect {
+    fn supports_double_quoted_identifiers(&self) -> bool {
+        true
+    }
+
+    fn is_reserved_word(&self, word: &str) -> bool {
+        matches!(word.to_ascii_uppercase().as_str(), "ILIKE" | "RETURNING")
+    }
+}
+
+/// The text [`normalize`] hands to the grammar, plus a byte-offset map back
+/// to the original input so a parse error can be reported against the
+/// source the user actually typed instead of the stripped text.
+pub(crate) struct Normalized {
+    pub text: String,
+    /// `(byte offset in text, byte offset in the original input)` at the
+    /// start of every char copied into `text`, in ascending order, with a
+    /// trailing sentinel `(text.len(), input.len())` for end-of-input
+    /// positions.
+    offsets: Vec<(usize, usize)>,
+}
+
+impl Normalized {
+    /// Maps a byte offset into `self.text` back to the corresponding byte
+    /// offset in the original input that produced it.
+    pub(crate) fn original_offset(&self, pos: usize) -> usize {
+        match self.offsets.binary_search_by_key(&pos, |&(text_pos, _)| text_pos) {
+            Ok(i) => self.offsets[i].1,
+            Err(0) => 0,
+            Err(i) => {
+                let (text_pos, input_pos) = self.offsets[i - 1];
+                input_pos + (pos - text_pos)
+            }
+        }
+    }
+}
+
+/// Strips this dialect's supported quote characters out of `input`,
+/// producing text the grammar's bare `identifier` rule can parse. Quote
+/// styles the dialect does not support are left untouched, so an
+/// unsupported quote still fails to parse as an identifier, same as today.
+///
+/// A quote character is only treated as an identifier delimiter outside a
+/// `'...'` string literal — `'say "hi"'` is copied through verbatim under
+/// `ansi`/`postgres` rather than having its embedded `"` consumed as a
+/// quoted identifier, which would otherwise corrupt the literal or, if the
+/// string contains no closing quote char, run past the end of the literal
+/// looking for one.
+pub(crate) fn normalize(dialect: &dyn Dialect, input: &str) -> Normalized {
+    let mut out = String::with_capacity(input.len());
+    let mut offsets = Vec::with_capacity(input.len());
+    let mut chars = input.char_indices().peekable();
+    let mut in_string = false;
+
+    while let Some((idx, c)) = chars.next() {
+        match c {
+            '\'' => {
+                in_string = !in_string;
+                offsets.push((out.len(), idx));
+                out.push(c);
+            }
+            '"' if !in_string && dialect.supports_double_quoted_identifiers() => {
+                consume_quoted(&mut chars, &mut out, &mut offsets, '"')
+            }
+            '`' if !in_string && dialect.supports_backtick_quotes() => {
+                consume_quoted(&mut chars, &mut out, &mut offsets, '`')
+            }
+            '[' if !in_string && dialect.supports_bracket_quotes() => {
+                consume_quoted(&mut chars, &mut out, &mut offsets, ']')
+            }
+            other => {
+                offsets.push((out.len(), idx));
+                out.push(other);
+            }
+        }
+    }
+    offsets.push((out.len(), input.len()));
+
+    Normalized { text: out, offsets }
+}
+
+/// Copies characters up to (and excluding) the matching `close` quote,
+/// consuming the closing quote itself, and records each copied char's
+/// original position in `offsets`.
+fn consume_quoted(
+    chars: &mut std::iter::Peekable<std::str::CharIndices>,
+    out: &mut String,
+    offsets: &mut Vec<(usize, usize)>,
+    close: char,
+) {
+    for (idx, c) in chars.by_ref() {
+        if c == close {
+            break;
+        }
+        offsets.push((out.len(), idx));
+        out.push(c);
+    }
+}
+
+/// Walks every identifier in `query` — select columns and their aliases,
+/// function names, table names, and `WHERE` left-hand sides — and returns
+/// the first one the dialect reserves.
+pub(crate) fn validate_identifiers(
+    dialect: &dyn Dialect,
+    query: &SelectQuery,
+) -> Result<(), String> {
+    for item in &query.columns {
+        validate_select_item(dialect, item)?;
+    }
+    validate_table(dialect, &query.table)?;
+    if let Some(where_clause) = &query.where_clause {
+        validate_where_expr(dialect, where_clause)?;
+    }
+
+    Ok(())
+}
+
+fn validate_identifier(dialect: &dyn Dialect, name: &str) -> Result<(), String> {
+    if dialect.is_reserved_word(name) {
+        return Err(name.to_string());
+    }
+
+    Ok(())
+}
+
+fn validate_select_item(dialect: &dyn Dialect, item: &SelectItem) -> Result<(), String> {
+    match item {
+        SelectItem::Column { name, alias } => {
+            if name != "*" {
+                validate_identifier(dialect, name)?;
+            }
+            if let Some(alias) = alias {
+                validate_identifier(dialect, alias)?;
+            }
+        }
+        SelectItem::Function {
+            name,
+            arguments,
+            alias,
+        } => {
+            validate_identifier(dialect, name)?;
+            for argument in arguments {
+                validate_select_item(dialect, argument)?;
+            }
+            if let Some(alias) = alias {
+                validate_identifier(dialect, alias)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_table(dialect: &dyn Dialect, table: &Table) -> Result<(), String> {
+    match table {
+        Table::Simple { name, alias } => {
+            validate_identifier(dialect, name)?;
+            if let Some(alias) = alias {
+                validate_identifier(dialect, alias)?;
+            }
+        }
+        Table::Subquery { query, alias } => {
+            validate_identifiers(dialect, query)?;
+            if let Some(alias) = alias {
+                validate_identifier(dialect, alias)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_where_expr(dialect: &dyn Dialect, expr: &WhereExpr) -> Result<(), String> {
+    match expr {
+        WhereExpr::Condition(condition) => validate_identifier(dialect, &condition.left),
+        WhereExpr::And(left, right) | WhereExpr::Or(left, right) => {
+            validate_where_expr(dialect, left)?;
+            validate_where_expr(dialect, right)
+        }
+        WhereExpr::Not(inner) => validate_where_expr(dialect, inner),
+    }
+}
@@ -0,0 +1,401 @@
+//! Executes a parsed [`SelectQuery`] against an in-memory, file-backed table.
+//!
+//! This is a minimal row engine, not a query planner: the whole source file
+//! is loaded into memory as a `Vec<Row>`, the `WHERE` predicate is evaluated
+//! per row, and the selected columns are projected out. It exists to give
+//! the AST a concrete consumer beyond inspection.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use thiserror::Error;
+
+use crate::{Condition, SelectItem, SelectQuery, Value, WhereExpr};
+
+/// A single row of a loaded table, keyed by column name.
+///
+/// Backed by a `HashMap` for lookups, but tracks insertion order separately
+/// so `SELECT *` can print columns in the order they appeared in the source
+/// file instead of `HashMap`'s unspecified iteration order.
+#[derive(Debug, Default)]
+pub struct Row {
+    columns: Vec<String>,
+    values: HashMap<String, Value>,
+}
+
+impl Row {
+    /// Creates an empty row.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts `key` with `value`, appending `key` to the column order if
+    /// it hasn't been seen before; re-inserting an existing key updates its
+    /// value in place without moving it.
+    pub fn insert(&mut self, key: String, value: Value) {
+        if self.values.insert(key.clone(), value).is_none() {
+            self.columns.push(key);
+        }
+    }
+
+    /// Looks up a column's value by name.
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.values.get(key)
+    }
+
+    /// Column names in the order they were first inserted.
+    pub fn keys(&self) -> impl Iterator<Item = &String> {
+        self.columns.iter()
+    }
+
+    /// `(key, value)` pairs in the order keys were first inserted.
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &Value)> {
+        self.columns.iter().map(move |key| (key, &self.values[key]))
+    }
+}
+
+impl Extend<(String, Value)> for Row {
+    fn extend<T: IntoIterator<Item = (String, Value)>>(&mut self, iter: T) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl FromIterator<(String, Value)> for Row {
+    fn from_iter<T: IntoIterator<Item = (String, Value)>>(iter: T) -> Self {
+        let mut row = Row::new();
+        row.extend(iter);
+        row
+    }
+}
+
+/// Possible errors when loading or executing a query against a data source.
+#[derive(Debug, Error)]
+pub enum ExecError {
+    #[error("Failed to read data file {0}: {1}")]
+    FileReadError(String, std::io::Error),
+
+    #[error("Unsupported data file extension: {0}")]
+    UnsupportedFileType(String),
+
+    #[error("Malformed CSV row: expected {0} columns, found {1}")]
+    MalformedCsvRow(usize, usize),
+
+    #[error("Malformed JSON line: {0}")]
+    MalformedJsonLine(String),
+
+    #[error("Unsupported WHERE operator for execution: {0}")]
+    UnsupportedOperator(String),
+
+    #[error("Column not found: {0}")]
+    ColumnNotFound(String),
+}
+
+/// Loads a table from a CSV or line-delimited JSON file, based on its
+/// extension (`.csv` or `.json`/`.jsonl`).
+pub fn load_table(path: &Path) -> Result<Vec<Row>, ExecError> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| ExecError::FileReadError(path.display().to_string(), e))?;
+
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("csv") => load_csv(&content),
+        Some("json") | Some("jsonl") => load_jsonl(&content),
+        other => Err(ExecError::UnsupportedFileType(
+            other.unwrap_or_default().to_string(),
+        )),
+    }
+}
+
+fn load_csv(content: &str) -> Result<Vec<Row>, ExecError> {
+    let mut lines = content.lines().filter(|line| !line.trim().is_empty());
+
+    let headers: Vec<&str> = match lines.next() {
+        Some(header_line) => header_line.split(',').map(str::trim).collect(),
+        None => return Ok(Vec::new()),
+    };
+
+    lines
+        .map(|line| {
+            let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+            if fields.len() != headers.len() {
+                return Err(ExecError::MalformedCsvRow(headers.len(), fields.len()));
+            }
+
+            Ok(headers
+                .iter()
+                .zip(fields)
+                .map(|(&header, field)| (header.to_string(), infer_scalar(field)))
+                .collect())
+        })
+        .collect()
+}
+
+fn load_jsonl(content: &str) -> Result<Vec<Row>, ExecError> {
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| parse_json_object(line.trim()))
+        .collect()
+}
+
+/// Parses a single-line `{"key": value, ...}` JSON object into a [`Row`].
+/// This is a deliberately small, flat-object-only JSON reader: it exists to
+/// support line-delimited JSON rows, not arbitrary nested documents.
+fn parse_json_object(line: &str) -> Result<Row, ExecError> {
+    let body = line
+        .strip_prefix('{')
+        .and_then(|s| s.strip_suffix('}'))
+        .ok_or_else(|| ExecError::MalformedJsonLine(line.to_string()))?;
+
+    let mut row = Row::new();
+    for entry in split_top_level(body, ',') {
+        let entry = entry.trim();
+        if entry.is_empty() {
+            continue;
+        }
+
+        let (key, value) = entry
+            .split_once(':')
+            .ok_or_else(|| ExecError::MalformedJsonLine(line.to_string()))?;
+
+        let key = key.trim().trim_matches('"').to_string();
+        row.insert(key, parse_json_value(value.trim()));
+    }
+
+    Ok(row)
+}
+
+fn parse_json_value(text: &str) -> Value {
+    if let Some(inner) = text.strip_prefix('"').and_then(|s| s.strip_suffix('"')) {
+        return Value::String(inner.to_string());
+    }
+    match text {
+        "true" => Value::Boolean(true),
+        "false" => Value::Boolean(false),
+        "null" => Value::Null,
+        _ => infer_scalar(text),
+    }
+}
+
+/// Splits on a separator while ignoring separators inside `"..."` strings.
+fn split_top_level(text: &str, separator: char) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut in_string = false;
+
+    for c in text.chars() {
+        match c {
+            '"' => {
+                in_string = !in_string;
+                current.push(c);
+            }
+            c if c == separator && !in_string => {
+                parts.push(std::mem::take(&mut current));
+            }
+            c => current.push(c),
+        }
+    }
+    parts.push(current);
+
+    parts
+}
+
+/// Infers a [`Value`] for a bare (unquoted) CSV or JSON scalar.
+fn infer_scalar(text: &str) -> Value {
+    if let Ok(n) = text.parse::<i64>() {
+        return Value::Number(n);
+    }
+    if let Ok(n) = text.parse::<f64>() {
+        return Value::Float(n);
+    }
+    if let Ok(b) = text.parse::<bool>() {
+        return Value::Boolean(b);
+    }
+    Value::String(text.to_string())
+}
+
+/// Runs `query` against `rows`: evaluates the `WHERE` clause, then projects
+/// the selected columns for every matching row.
+pub fn execute_query(query: &SelectQuery, rows: &[Row]) -> Result<Vec<Row>, ExecError> {
+    let mut matching = Vec::new();
+    for row in rows {
+        if eval_where(query.where_clause.as_ref(), row)? {
+            matching.push(project(&query.columns, row)?);
+        }
+    }
+
+    Ok(matching)
+}
+
+fn eval_where(where_clause: Option<&WhereExpr>, row: &Row) -> Result<bool, ExecError> {
+    match where_clause {
+        None => Ok(true),
+        Some(expr) => eval_where_expr(expr, row),
+    }
+}
+
+fn eval_where_expr(expr: &WhereExpr, row: &Row) -> Result<bool, ExecError> {
+    match expr {
+        WhereExpr::Condition(condition) => eval_condition(condition, row),
+        WhereExpr::And(left, right) => {
+            Ok(eval_where_expr(left, row)? && eval_where_expr(right, row)?)
+        }
+        WhereExpr::Or(left, right) => {
+            Ok(eval_where_expr(left, row)? || eval_where_expr(right, row)?)
+        }
+        WhereExpr::Not(inner) => Ok(!eval_where_expr(inner, row)?),
+    }
+}
+
+fn eval_condition(condition: &Condition, row: &Row) -> Result<bool, ExecError> {
+    let actual = row
+        .get(&condition.left)
+        .ok_or_else(|| ExecError::ColumnNotFound(condition.left.clone()))?;
+
+    match condition.operator.as_str() {
+        "=" => Ok(values_equal(actual, &condition.right)),
+        "<" => Ok(compare_values(actual, &condition.right) == Some(std::cmp::Ordering::Less)),
+        ">" => Ok(compare_values(actual, &condition.right) == Some(std::cmp::Ordering::Greater)),
+        other => Err(ExecError::UnsupportedOperator(other.to_string())),
+    }
+}
+
+fn values_equal(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        (Value::Number(a), Value::Number(b)) => a == b,
+        (Value::Float(a), Value::Float(b)) => a == b,
+        (Value::Number(a), Value::Float(b)) | (Value::Float(b), Value::Number(a)) => {
+            (*a as f64) == *b
+        }
+        (Value::String(a), Value::String(b)) => a == b,
+        (Value::Boolean(a), Value::Boolean(b)) => a == b,
+        (Value::Null, Value::Null) => true,
+        _ => false,
+    }
+}
+
+fn compare_values(a: &Value, b: &Value) -> Option<std::cmp::Ordering> {
+    match (a, b) {
+        (Value::Number(a), Value::Number(b)) => a.partial_cmp(b),
+        (Value::Float(a), Value::Float(b)) => a.partial_cmp(b),
+        (Value::Number(a), Value::Float(b)) => (*a as f64).partial_cmp(b),
+        (Value::Float(a), Value::Number(b)) => a.partial_cmp(&(*b as f64)),
+        (Value::String(a), Value::String(b)) => a.partial_cmp(b),
+        _ => None,
+    }
+}
+
+fn project(columns: &[SelectItem], row: &Row) -> Result<Row, ExecError> {
+    let mut projected = Row::new();
+
+    for column in columns {
+        match column {
+            SelectItem::Column { name, alias } => {
+                if name == "*" {
+                    projected.extend(row.iter().map(|(k, v)| (k.clone(), clone_value(v))));
+                    continue;
+                }
+                let value = row
+                    .get(name)
+                    .ok_or_else(|| ExecError::ColumnNotFound(name.clone()))?;
+                projected.insert(
+                    alias.clone().unwrap_or_else(|| name.clone()),
+                    clone_value(value),
+                );
+            }
+            SelectItem::Function { name, .. } => {
+                return Err(ExecError::UnsupportedOperator(format!(
+                    "function calls are not executable yet: {name}"
+                )));
+            }
+        }
+    }
+
+    Ok(projected)
+}
+
+/// Renders query results as a simple `|`-separated table, using the select
+/// list to decide column order even when a row omits a column.
+pub fn format_rows(query: &SelectQuery, rows: &[Row]) -> String {
+    let headers = column_headers(query, rows);
+    if headers.is_empty() {
+        return "(no columns)".to_string();
+    }
+
+    let mut out = String::new();
+    out.push_str(&headers.join(" | "));
+    out.push('\n');
+
+    for row in rows {
+        let line = headers
+            .iter()
+            .map(|header| row.get(header).map(format_cell).unwrap_or_default())
+            .collect::<Vec<_>>()
+            .join(" | ");
+        out.push_str(&line);
+        out.push('\n');
+    }
+
+    out.push_str(&format!(
+        "({} row{})",
+        rows.len(),
+        if rows.len() == 1 { "" } else { "s" }
+    ));
+
+    out
+}
+
+/// Renders a single result cell for the table produced by [`format_rows`].
+///
+/// `Value`'s `Display` impl renders the SQL-literal form (e.g. a string
+/// quoted as `'alice'`), which is right for round-tripping a query back to
+/// SQL but wrong here: this is a results table, not SQL source, so string
+/// cells are printed bare.
+fn format_cell(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Determines the column order for printing: the select list's column names,
+/// with `*` expanded to every key seen across the result rows.
+fn column_headers(query: &SelectQuery, rows: &[Row]) -> Vec<String> {
+    let mut headers = Vec::new();
+    for item in &query.columns {
+        match item {
+            SelectItem::Column { name, .. } if name == "*" => {
+                let mut star_keys: Vec<String> = Vec::new();
+                for row in rows {
+                    for key in row.keys() {
+                        if !star_keys.contains(key) {
+                            star_keys.push(key.clone());
+                        }
+                    }
+                }
+                headers.extend(star_keys);
+            }
+            SelectItem::Column { name, alias } => {
+                headers.push(alias.clone().unwrap_or_else(|| name.clone()));
+            }
+            SelectItem::Function { name, alias, .. } => {
+                headers.push(alias.clone().unwrap_or_else(|| name.clone()));
+            }
+        }
+    }
+
+    headers
+}
+
+fn clone_value(value: &Value) -> Value {
+    match value {
+        Value::Number(n) => Value::Number(*n),
+        Value::Float(n) => Value::Float(*n),
+        Value::String(s) => Value::String(s.clone()),
+        Value::Boolean(b) => Value::Boolean(*b),
+        Value::Null => Value::Null,
+        Value::List(values) => Value::List(values.iter().map(clone_value).collect()),
+    }
+}
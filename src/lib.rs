@@ -3,8 +3,15 @@
 use pest::iterators::Pair;
 use pest::Parser;
 use pest_derive::Parser;
+use serde::Serialize;
 use thiserror::Error;
 
+pub mod dialect;
+pub mod exec;
+pub mod repl;
+
+use dialect::{Dialect, GenericDialect};
+
 /// Main module that contains rules for parser
 #[derive(Parser)]
 #[grammar = "./sql.pest"]
@@ -12,18 +19,36 @@ pub struct SQLParser;
 
 /// Main structure for storing a SQL select query.
 /// Contains selected columns, the table, and an optional WHERE filter.
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct SelectQuery {
     /// List of columns or functions to select.
     pub columns: Vec<SelectItem>,
     /// The table to select data from.
     pub table: Table,
     /// Filtering conditions in WHERE, if present.
-    pub where_clause: Option<Condition>,
+    pub where_clause: Option<WhereExpr>,
+    /// Maximum number of rows to return, if a LIMIT clause is present.
+    pub limit: Option<u64>,
+    /// Number of rows to skip before returning results, if an OFFSET clause is present.
+    pub offset: Option<u64>,
+}
+
+/// A boolean expression tree for a `WHERE` clause, supporting arbitrarily
+/// nested `AND` / `OR` / `NOT` combinations of conditions.
+#[derive(Debug, Serialize)]
+pub enum WhereExpr {
+    /// A single comparison, e.g. `price > 100`.
+    Condition(Condition),
+    /// Both sides must hold.
+    And(Box<WhereExpr>, Box<WhereExpr>),
+    /// Either side must hold.
+    Or(Box<WhereExpr>, Box<WhereExpr>),
+    /// The inner expression must not hold.
+    Not(Box<WhereExpr>),
 }
 
 /// Possible values for SQL expressions.
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub enum Value {
     /// A number.
     Number(i64),
@@ -31,11 +56,42 @@ pub enum Value {
     String(String),
     /// A boolean (true or false).
     Boolean(bool),
+    /// A floating-point number.
+    Float(f64),
+    /// The absence of a value.
+    Null,
+    /// A parenthesized, comma-separated list of values, as used by `IN`.
+    List(Vec<Value>),
+}
+
+/// Tags the shape of a [`Value`] without needing to match every variant.
+#[derive(Debug, PartialEq, Eq)]
+pub enum ValueType {
+    Number,
+    Float,
+    String,
+    Boolean,
+    Null,
+    List,
+}
+
+impl Value {
+    /// Returns the [`ValueType`] of this value.
+    pub fn value_type(&self) -> ValueType {
+        match self {
+            Value::Number(_) => ValueType::Number,
+            Value::Float(_) => ValueType::Float,
+            Value::String(_) => ValueType::String,
+            Value::Boolean(_) => ValueType::Boolean,
+            Value::Null => ValueType::Null,
+            Value::List(_) => ValueType::List,
+        }
+    }
 }
 
 /// A condition for where.
 /// Contains the column name, comparison operator, and the value to compare.
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct Condition {
     /// The left part of the condition, like a column name.
     pub left: String,
@@ -46,24 +102,189 @@ pub struct Condition {
 }
 
 /// Types of items in select: a simple column, a function, or a star (*).
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub enum SelectItem {
-    /// A column with a name.
-    Column(String),
-    /// A function call, with a function name and arguments.
+    /// A column with a name, and an optional `AS` alias.
+    Column { name: String, alias: Option<String> },
+    /// A function call, with a function name, arguments, and an optional `AS` alias.
     Function {
         name: String,
         arguments: Vec<SelectItem>,
+        alias: Option<String>,
     },
 }
 
 /// The table for select, which can be a simple table or a subquery.
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub enum Table {
-    /// A table name.
-    Simple(String),
-    /// A subquery SELECT.
-    Subquery(Box<SelectQuery>),
+    /// A table name, with an optional `AS` alias.
+    Simple { name: String, alias: Option<String> },
+    /// A subquery SELECT, with an optional `AS` alias.
+    Subquery {
+        query: Box<SelectQuery>,
+        alias: Option<String>,
+    },
+}
+
+impl std::fmt::Display for SelectQuery {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let columns = self
+            .columns
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        write!(f, "SELECT {} FROM {}", columns, self.table)?;
+
+        if let Some(where_clause) = &self.where_clause {
+            write!(f, " WHERE {}", fmt_where_expr(where_clause, 0))?;
+        }
+        if let Some(limit) = self.limit {
+            write!(f, " LIMIT {}", limit)?;
+        }
+        if let Some(offset) = self.offset {
+            write!(f, " OFFSET {}", offset)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl SelectQuery {
+    /// Renders this query back into normalized SQL text.
+    ///
+    /// This is a round-trip counterpart to [`parse_query`]: consistent
+    /// keyword casing, single-space separators, and canonical identifier
+    /// quoting, regardless of how the original source was formatted.
+    pub fn to_sql(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl std::fmt::Display for SelectItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SelectItem::Column { name, alias } => {
+                write!(f, "{}", name)?;
+                if let Some(alias) = alias {
+                    write!(f, " AS {}", alias)?;
+                }
+            }
+            SelectItem::Function {
+                name,
+                arguments,
+                alias,
+            } => {
+                let arguments = arguments
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                write!(f, "{}({})", name, arguments)?;
+                if let Some(alias) = alias {
+                    write!(f, " AS {}", alias)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for Table {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Table::Simple { name, alias } => {
+                write!(f, "{}", name)?;
+                if let Some(alias) = alias {
+                    write!(f, " AS {}", alias)?;
+                }
+            }
+            Table::Subquery { query, alias } => {
+                write!(f, "({})", query)?;
+                if let Some(alias) = alias {
+                    write!(f, " AS {}", alias)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for Condition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {} {}",
+            self.left,
+            self.operator.to_uppercase(),
+            self.right
+        )
+    }
+}
+
+impl std::fmt::Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Number(n) => write!(f, "{}", n),
+            Value::Float(n) => write!(f, "{:?}", n),
+            Value::String(s) => write!(f, "'{}'", s),
+            Value::Boolean(b) => write!(f, "{}", if *b { "TRUE" } else { "FALSE" }),
+            Value::Null => write!(f, "NULL"),
+            Value::List(values) => {
+                let values = values
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                write!(f, "({})", values)
+            }
+        }
+    }
+}
+
+/// Operator precedence of a [`WhereExpr`] node: higher binds tighter.
+/// Used by [`fmt_where_expr`] to decide when a nested expression needs
+/// parentheses to preserve its original meaning when rendered back to SQL.
+fn where_expr_precedence(expr: &WhereExpr) -> u8 {
+    match expr {
+        WhereExpr::Or(..) => 1,
+        WhereExpr::And(..) => 2,
+        WhereExpr::Not(..) => 3,
+        WhereExpr::Condition(..) => 4,
+    }
+}
+
+/// Renders a child expression, wrapping it in parentheses if its precedence
+/// is lower than `min_precedence` (i.e. rendering it bare would change its
+/// meaning once substituted back into the parent expression).
+fn fmt_where_expr(expr: &WhereExpr, min_precedence: u8) -> String {
+    let rendered = match expr {
+        WhereExpr::Condition(condition) => condition.to_string(),
+        // The grammar only accepts a condition or a parenthesized expression
+        // after `NOT`, so a nested `NOT` is parenthesized as well.
+        WhereExpr::Not(inner) => {
+            format!("NOT {}", fmt_where_expr(inner, where_expr_precedence(expr) + 1))
+        }
+        WhereExpr::And(left, right) => format!(
+            "{} AND {}",
+            fmt_where_expr(left, where_expr_precedence(expr)),
+            fmt_where_expr(right, where_expr_precedence(expr))
+        ),
+        WhereExpr::Or(left, right) => format!(
+            "{} OR {}",
+            fmt_where_expr(left, where_expr_precedence(expr)),
+            fmt_where_expr(right, where_expr_precedence(expr))
+        ),
+    };
+
+    if where_expr_precedence(expr) < min_precedence {
+        format!("({})", rendered)
+    } else {
+        rendered
+    }
 }
 
 /// Possible errors when parsing an SQL query.
@@ -108,6 +329,9 @@ pub enum ParseError {
     #[error("Invalid number: {0}")]
     InvalidNumber(#[from] std::num::ParseIntError),
 
+    #[error("Invalid float: {0}")]
+    InvalidFloat(#[from] std::num::ParseFloatError),
+
     #[error("Unexpected rule in value")]
     UnexpectedRuleInValue,
 
@@ -117,8 +341,107 @@ pub enum ParseError {
     #[error("Function name missing")]
     FunctionNameMissing,
 
+    #[error("Invalid limit: {0}")]
+    InvalidLimit(String),
+
+    #[error("Invalid offset: {0}")]
+    InvalidOffset(String),
+
+    #[error("Mismatched operator and value: {0}")]
+    MismatchedOperatorValue(String),
+
     #[error("Unexpected rule in select_list")]
     UnexpectedRuleInSelectListOther,
+
+    #[error("identifier `{0}` is reserved in this dialect")]
+    ReservedWord(String),
+
+    #[error(
+        "syntax error at line {line}, column {column}: expected {}{}\n{line_text}\n{}^",
+        expected.join(" or "),
+        found.as_ref().map_or(String::new(), |f| format!(", found `{f}`")),
+        " ".repeat(column.saturating_sub(1))
+    )]
+    SyntaxError {
+        line: usize,
+        column: usize,
+        expected: Vec<String>,
+        found: Option<String>,
+        /// The full text of the offending line, so the `^` caret below it
+        /// points at real source instead of blank space.
+        line_text: String,
+    },
+}
+
+/// Turns a pest parsing failure against `normalized.text` into a
+/// [`ParseError::SyntaxError`], capturing the line/column of the failure,
+/// the set of rules pest expected to see there, and a short snippet of what
+/// was actually found.
+///
+/// pest's own `err.line_col` is measured against `normalized.text`, which is
+/// not what the user typed whenever the dialect stripped any quoting before
+/// that point, so the position is remapped through
+/// [`Normalized::original_offset`] and recomputed against `input` instead.
+fn build_syntax_error(
+    err: pest::error::Error<Rule>,
+    normalized: &dialect::Normalized,
+    input: &str,
+) -> ParseError {
+    let expected = match &err.variant {
+        pest::error::ErrorVariant::ParsingError { positives, .. } => {
+            positives.iter().map(|rule| format!("{rule:?}")).collect()
+        }
+        pest::error::ErrorVariant::CustomError { message } => vec![message.clone()],
+    };
+
+    let (pos, found) = match err.location {
+        pest::error::InputLocation::Pos(normalized_pos) => {
+            let pos = normalized.original_offset(normalized_pos);
+            let token: String = input[pos..]
+                .chars()
+                .take_while(|c| !c.is_whitespace())
+                .collect();
+            (pos, (!token.is_empty()).then_some(token))
+        }
+        pest::error::InputLocation::Span((normalized_start, normalized_end)) => {
+            let start = normalized.original_offset(normalized_start);
+            let end = normalized.original_offset(normalized_end);
+            (start, Some(input[start..end].to_string()))
+        }
+    };
+    let (line, column) = line_col(input, pos);
+    let line_text = source_line(input, pos).to_string();
+
+    ParseError::SyntaxError {
+        line,
+        column,
+        expected,
+        found,
+        line_text,
+    }
+}
+
+/// Computes the 1-based `(line, column)` of byte offset `pos` in `input`,
+/// counting chars rather than bytes so multi-byte characters don't throw the
+/// column off.
+fn line_col(input: &str, pos: usize) -> (usize, usize) {
+    let prefix = &input[..pos.min(input.len())];
+    let line = prefix.matches('\n').count() + 1;
+    let column = match prefix.rfind('\n') {
+        Some(newline) => prefix[newline + '\n'.len_utf8()..].chars().count() + 1,
+        None => prefix.chars().count() + 1,
+    };
+    (line, column)
+}
+
+/// Returns the full text (no trailing newline) of the line containing byte
+/// offset `pos` in `input`, so a [`ParseError::SyntaxError`] can print the
+/// offending source line above its `^` caret.
+fn source_line(input: &str, pos: usize) -> &str {
+    let pos = pos.min(input.len());
+    let start = input[..pos].rfind('\n').map_or(0, |i| i + 1);
+    let end = input[pos..].find('\n').map_or(input.len(), |i| pos + i);
+    &input[start..end]
 }
 
 /// Function to parse an SQL query.
@@ -127,22 +450,183 @@ pub enum ParseError {
 /// # Example
 ///
 /// ```
+/// use sql_select_parser::parse_query;
+///
 /// let query = "select name from users where age > 20";
 /// let result = parse_query(query);
 /// assert!(result.is_ok());
 /// ```
 pub fn parse_query(input: &str) -> Result<SelectQuery, ParseError> {
-    let mut pairs = SQLParser::parse(Rule::select_query, input)
-        .map_err(|e| ParseError::ParsingError(e.to_string()))?;
-    let pair = pairs.next().ok_or(ParseError::NoQueryFound)?;
+    parse_query_with_dialect(input, &GenericDialect)
+}
+
+/// Parses an SQL query the same way as [`parse_query`], but under a specific
+/// [`Dialect`]: quoted identifiers the dialect supports (backtick, double
+/// quote, or bracket) are normalized before parsing, and the resulting AST
+/// is checked against the dialect's reserved words.
+///
+/// # Example
+///
+/// ```
+/// use sql_select_parser::{parse_query_with_dialect, dialect::MySqlDialect};
+///
+/// let query = "select name from `users`";
+/// let result = parse_query_with_dialect(query, &MySqlDialect);
+/// assert!(result.is_ok());
+/// ```
+pub fn parse_query_with_dialect(
+    input: &str,
+    dialect: &dyn Dialect,
+) -> Result<SelectQuery, ParseError> {
+    let normalized = dialect::normalize(dialect, input);
+
+    let mut pairs = SQLParser::parse(Rule::sql, &normalized.text)
+        .map_err(|e| build_syntax_error(e, &normalized, input))?;
+    let sql_pair = pairs.next().ok_or(ParseError::NoQueryFound)?;
+    let pair = sql_pair
+        .into_inner()
+        .find(|p| p.as_rule() == Rule::select_query)
+        .ok_or(ParseError::NoQueryFound)?;
+
+    let query = build_query_structure(pair)?;
+    dialect::validate_identifiers(dialect, &query).map_err(ParseError::ReservedWord)?;
 
-    build_query_structure(pair)
+    Ok(query)
+}
+
+/// Splits `input` on statement-terminating semicolons and parses each
+/// statement independently, in source order.
+///
+/// Unlike [`parse_query`], a failed statement does not abort the batch: its
+/// slot in the returned `Vec` holds the error (with the line/column of the
+/// failure, relative to the whole script) while parsing continues with the
+/// next statement. This is what lets `.sql` dump files with many statements
+/// be parsed in one pass, reporting every bad statement instead of just the
+/// first.
+pub fn parse_script(input: &str) -> Vec<Result<SelectQuery, ParseError>> {
+    parse_script_with_dialect(input, &GenericDialect)
+}
+
+/// Parses a multi-statement script the same way as [`parse_script`], but
+/// under a specific [`Dialect`], as [`parse_query_with_dialect`] does for a
+/// single statement.
+pub fn parse_script_with_dialect(
+    input: &str,
+    dialect: &dyn Dialect,
+) -> Vec<Result<SelectQuery, ParseError>> {
+    split_statements(input)
+        .into_iter()
+        .filter(|(_, statement)| !statement.is_empty())
+        .map(|(start, statement)| {
+            parse_query_with_dialect(statement, dialect)
+                .map_err(|e| remap_script_error(e, statement, start, input))
+        })
+        .collect()
+}
+
+/// Splits `input` into `(start, statement)` pairs on `;` characters that
+/// fall outside single-quoted strings, where `statement` is trimmed of
+/// surrounding whitespace and `start` is that trimmed text's byte offset in
+/// `input`. Because each `statement` is therefore an exact substring of
+/// `input`, a position found while parsing it can be translated back to a
+/// whole-script position by simple addition, the same way dialect
+/// normalization's offset map does for dialect-stripped quoting (see
+/// [`remap_script_error`]).
+fn split_statements(input: &str) -> Vec<(usize, &str)> {
+    let mut statements = Vec::new();
+    let mut statement_start = 0usize;
+    let mut in_string = false;
+
+    for (idx, c) in input.char_indices() {
+        match c {
+            '\'' => in_string = !in_string,
+            ';' if !in_string => {
+                statements.push(trim_with_offset(&input[statement_start..idx], statement_start));
+                statement_start = idx + 1;
+            }
+            _ => {}
+        }
+    }
+    statements.push(trim_with_offset(&input[statement_start..], statement_start));
+
+    statements
+}
+
+/// Trims `text` and returns `(offset, trimmed)`, where `offset` is `base`
+/// shifted by however many leading bytes were trimmed off, so `offset` is
+/// `trimmed`'s byte position in whatever larger string `text` (at `base`)
+/// came from.
+fn trim_with_offset(text: &str, base: usize) -> (usize, &str) {
+    let leading = text.len() - text.trim_start().len();
+    (base + leading, text.trim())
+}
+
+/// Translates a [`ParseError::SyntaxError`] produced by parsing a single
+/// extracted `statement` back into a whole-script position: `statement` is
+/// an exact substring of `input` starting at byte `start`, so the error's
+/// statement-relative `(line, column)` is turned back into a byte offset via
+/// [`offset_for_line_col`], shifted by `start`, and re-resolved against
+/// `input` with [`line_col`]/[`source_line`] — the same pos-based approach
+/// [`build_syntax_error`] uses for dialect normalization. Other error
+/// variants carry no position and pass through unchanged.
+fn remap_script_error(error: ParseError, statement: &str, start: usize, input: &str) -> ParseError {
+    match error {
+        ParseError::SyntaxError {
+            line,
+            column,
+            expected,
+            found,
+            ..
+        } => {
+            let pos = start + offset_for_line_col(statement, line, column);
+            let (line, column) = line_col(input, pos);
+            ParseError::SyntaxError {
+                line,
+                column,
+                expected,
+                found,
+                line_text: source_line(input, pos).to_string(),
+            }
+        }
+        other => other,
+    }
+}
+
+/// The inverse of [`line_col`]: the byte offset in `text` of the 1-based
+/// `(line, column)` position, counting chars per column the same way
+/// `line_col` counts them. A `column` past the end of its line clamps to
+/// the end of that line (or of `text`).
+fn offset_for_line_col(text: &str, line: usize, column: usize) -> usize {
+    let mut line_start = 0;
+    let mut current_line = 1;
+    if line > 1 {
+        for (idx, c) in text.char_indices() {
+            if c == '\n' {
+                current_line += 1;
+                if current_line == line {
+                    line_start = idx + 1;
+                    break;
+                }
+            }
+        }
+    }
+
+    let mut offset = line_start;
+    for (char_index, (byte_index, c)) in text[line_start..].char_indices().enumerate() {
+        if char_index + 1 == column || c == '\n' {
+            return line_start + byte_index;
+        }
+        offset = line_start + byte_index + c.len_utf8();
+    }
+    offset
 }
 
 fn build_query_structure(pair: Pair<Rule>) -> Result<SelectQuery, ParseError> {
     let mut columns: Vec<SelectItem> = Vec::new();
     let mut table: Option<Table> = None;
-    let mut where_clause: Option<Condition> = None;
+    let mut where_clause: Option<WhereExpr> = None;
+    let mut limit: Option<u64> = None;
+    let mut offset: Option<u64> = None;
 
     for inner in pair.into_inner() {
         match inner.as_rule() {
@@ -155,6 +639,12 @@ fn build_query_structure(pair: Pair<Rule>) -> Result<SelectQuery, ParseError> {
             Rule::where_clause => {
                 where_clause = Some(where_parser(inner)?);
             }
+            Rule::limit_clause => {
+                limit = Some(parse_natural_number(inner, ParseError::InvalidLimit)?);
+            }
+            Rule::offset_clause => {
+                offset = Some(parse_natural_number(inner, ParseError::InvalidOffset)?);
+            }
             _ => {}
         }
     }
@@ -163,9 +653,32 @@ fn build_query_structure(pair: Pair<Rule>) -> Result<SelectQuery, ParseError> {
         columns,
         table: table.ok_or(ParseError::TableNotSpecified)?,
         where_clause,
+        limit,
+        offset,
     })
 }
 
+/// Parses the `number` pair nested inside a `limit_clause`/`offset_clause` as a
+/// non-negative row count, rejecting negative or non-integer literals.
+///
+/// `invalid` builds the clause-specific error variant (`InvalidLimit` or
+/// `InvalidOffset`) so a bad `OFFSET` is reported as an offset error rather
+/// than being misattributed to `LIMIT`.
+fn parse_natural_number(
+    pair: Pair<Rule>,
+    invalid: impl Fn(String) -> ParseError,
+) -> Result<u64, ParseError> {
+    let number_pair = pair
+        .into_inner()
+        .find(|p| p.as_rule() == Rule::number)
+        .ok_or_else(|| invalid(String::new()))?;
+
+    let text = number_pair.as_str();
+    let value: i64 = text.parse().map_err(|_| invalid(text.to_string()))?;
+
+    u64::try_from(value).map_err(|_| invalid(text.to_string()))
+}
+
 fn selected_rows_parser(pair: Pair<Rule>) -> Result<Vec<SelectItem>, ParseError> {
     let mut selected_rows = Vec::new();
 
@@ -185,56 +698,69 @@ fn selected_rows_parser(pair: Pair<Rule>) -> Result<Vec<SelectItem>, ParseError>
 fn parse_select_item(pair: Pair<Rule>) -> Result<SelectItem, ParseError> {
     let mut inner_pairs = pair.into_inner();
 
-    if let Some(inner) = inner_pairs.next() {
-        match inner.as_rule() {
-            Rule::identifier => Ok(SelectItem::Column(inner.as_str().to_string())),
-            Rule::function_call => {
-                let mut parts = inner.into_inner();
-                let function = parts
-                    .next()
-                    .ok_or(ParseError::FunctionNameMissing)?
-                    .as_str()
-                    .to_string();
-                let arguments = parts
-                    .map(parse_select_item)
-                    .collect::<Result<Vec<_>, _>>()?;
-                Ok(SelectItem::Function {
-                    name: function,
-                    arguments,
-                })
-            }
-            Rule::star => Ok(SelectItem::Column("*".to_string())),
-            _ => Err(ParseError::UnexpectedRuleInSelectItem),
+    let base = inner_pairs.next().ok_or(ParseError::MissingSelectItem)?;
+    let alias = inner_pairs
+        .find(|p| p.as_rule() == Rule::identifier)
+        .map(|p| p.as_str().to_string());
+
+    match base.as_rule() {
+        Rule::identifier => Ok(SelectItem::Column {
+            name: base.as_str().to_string(),
+            alias,
+        }),
+        Rule::function_call => {
+            let mut parts = base.into_inner();
+            let function = parts
+                .next()
+                .ok_or(ParseError::FunctionNameMissing)?
+                .as_str()
+                .to_string();
+            let arguments = parts
+                .map(parse_select_item)
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(SelectItem::Function {
+                name: function,
+                arguments,
+                alias,
+            })
         }
-    } else {
-        Err(ParseError::MissingSelectItem)
+        Rule::star => Ok(SelectItem::Column {
+            name: "*".to_string(),
+            alias,
+        }),
+        _ => Err(ParseError::UnexpectedRuleInSelectItem),
     }
 }
 
 fn table_parser(pair: Pair<Rule>) -> Result<Table, ParseError> {
-    let inner = pair
-        .into_inner()
-        .next()
-        .ok_or(ParseError::MissingTableName)?;
+    let mut inner_pairs = pair.into_inner();
 
-    match inner.as_rule() {
-        Rule::identifier => Ok(Table::Simple(inner.as_str().to_string())),
+    let base = inner_pairs.next().ok_or(ParseError::MissingTableName)?;
+    let alias = inner_pairs
+        .find(|p| p.as_rule() == Rule::identifier)
+        .map(|p| p.as_str().to_string());
+
+    match base.as_rule() {
+        Rule::identifier => Ok(Table::Simple {
+            name: base.as_str().to_string(),
+            alias,
+        }),
         Rule::select_query => {
-            let subquery = build_query_structure(inner)?;
-            Ok(Table::Subquery(Box::new(subquery)))
+            let subquery = build_query_structure(base)?;
+            Ok(Table::Subquery {
+                query: Box::new(subquery),
+                alias,
+            })
         }
         _ => Err(ParseError::UnexpectedRuleInTable),
     }
 }
 
-fn where_parser(pair: Pair<Rule>) -> Result<Condition, ParseError> {
+fn where_parser(pair: Pair<Rule>) -> Result<WhereExpr, ParseError> {
     for inner in pair.into_inner() {
         match inner.as_rule() {
             Rule::WHERE => {}
-            Rule::condition => {
-                let condition = parse_condition(inner)?;
-                return Ok(condition);
-            }
+            Rule::or_expr => return parse_or_expr(inner),
             _ => {}
         }
     }
@@ -242,6 +768,64 @@ fn where_parser(pair: Pair<Rule>) -> Result<Condition, ParseError> {
     Err(ParseError::NoConditionInWhereClause)
 }
 
+/// Folds a left-associative `and_expr ("OR" and_expr)*` list into nested `Or` nodes.
+fn parse_or_expr(pair: Pair<Rule>) -> Result<WhereExpr, ParseError> {
+    let mut terms = pair.into_inner().filter(|p| p.as_rule() == Rule::and_expr);
+
+    let mut expr = parse_and_expr(terms.next().ok_or(ParseError::NoConditionInWhereClause)?)?;
+    for term in terms {
+        expr = WhereExpr::Or(Box::new(expr), Box::new(parse_and_expr(term)?));
+    }
+
+    Ok(expr)
+}
+
+/// Folds a left-associative `not_expr ("AND" not_expr)*` list into nested `And` nodes.
+fn parse_and_expr(pair: Pair<Rule>) -> Result<WhereExpr, ParseError> {
+    let mut terms = pair.into_inner().filter(|p| p.as_rule() == Rule::not_expr);
+
+    let mut expr = parse_not_expr(terms.next().ok_or(ParseError::NoConditionInWhereClause)?)?;
+    for term in terms {
+        expr = WhereExpr::And(Box::new(expr), Box::new(parse_not_expr(term)?));
+    }
+
+    Ok(expr)
+}
+
+fn parse_not_expr(pair: Pair<Rule>) -> Result<WhereExpr, ParseError> {
+    let mut negate = false;
+    let mut primary = None;
+
+    for inner in pair.into_inner() {
+        match inner.as_rule() {
+            Rule::NOT => negate = true,
+            Rule::primary => primary = Some(inner),
+            _ => {}
+        }
+    }
+
+    let expr = parse_primary(primary.ok_or(ParseError::NoConditionInWhereClause)?)?;
+
+    Ok(if negate {
+        WhereExpr::Not(Box::new(expr))
+    } else {
+        expr
+    })
+}
+
+fn parse_primary(pair: Pair<Rule>) -> Result<WhereExpr, ParseError> {
+    let inner = pair
+        .into_inner()
+        .next()
+        .ok_or(ParseError::NoConditionInWhereClause)?;
+
+    match inner.as_rule() {
+        Rule::condition => Ok(WhereExpr::Condition(parse_condition(inner)?)),
+        Rule::or_expr => parse_or_expr(inner),
+        _ => Err(ParseError::NoConditionInWhereClause),
+    }
+}
+
 fn parse_condition(pair: Pair<Rule>) -> Result<Condition, ParseError> {
     let mut inner_rules = pair.into_inner();
 
@@ -258,7 +842,13 @@ fn parse_condition(pair: Pair<Rule>) -> Result<Condition, ParseError> {
         .to_string();
 
     let right_pair = inner_rules.next().ok_or(ParseError::MissingRightOperand)?;
-    let right = parse_value(right_pair)?;
+    let right = match right_pair.as_rule() {
+        Rule::value_list => parse_value_list(right_pair)?,
+        Rule::value => parse_value(right_pair)?,
+        _ => return Err(ParseError::UnexpectedRuleInValue),
+    };
+
+    validate_operator_value(&operator, &right)?;
 
     Ok(Condition {
         left,
@@ -267,6 +857,51 @@ fn parse_condition(pair: Pair<Rule>) -> Result<Condition, ParseError> {
     })
 }
 
+/// Checks that `IN` is only ever paired with a value list, that `LIKE` is only
+/// ever paired with a string, and that no other operator takes a value list.
+fn validate_operator_value(operator: &str, value: &Value) -> Result<(), ParseError> {
+    let is_list = matches!(value, Value::List(_));
+
+    if operator.eq_ignore_ascii_case("IN") {
+        if !is_list {
+            return Err(ParseError::MismatchedOperatorValue(
+                "IN requires a parenthesized value list".to_string(),
+            ));
+        }
+        return Ok(());
+    }
+
+    if is_list {
+        return Err(ParseError::MismatchedOperatorValue(format!(
+            "{} cannot be used with a value list",
+            operator
+        )));
+    }
+
+    if operator.eq_ignore_ascii_case("LIKE") && !matches!(value, Value::String(_)) {
+        return Err(ParseError::MismatchedOperatorValue(
+            "LIKE requires a string value".to_string(),
+        ));
+    }
+
+    if operator.eq_ignore_ascii_case("IS") && !matches!(value, Value::Null) {
+        return Err(ParseError::MismatchedOperatorValue(
+            "IS requires NULL".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+fn parse_value_list(pair: Pair<Rule>) -> Result<Value, ParseError> {
+    let values = pair
+        .into_inner()
+        .map(parse_value)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Value::List(values))
+}
+
 fn parse_value(pair: Pair<Rule>) -> Result<Value, ParseError> {
     let inner_pair = pair
         .into_inner()
@@ -274,6 +909,10 @@ fn parse_value(pair: Pair<Rule>) -> Result<Value, ParseError> {
         .ok_or(ParseError::ExpectedInnerRuleForValue)?;
 
     match inner_pair.as_rule() {
+        Rule::float => {
+            let num = inner_pair.as_str().parse::<f64>()?;
+            Ok(Value::Float(num))
+        }
         Rule::number => {
             let num = inner_pair.as_str().parse::<i64>()?;
             Ok(Value::Number(num))
@@ -287,6 +926,7 @@ fn parse_value(pair: Pair<Rule>) -> Result<Value, ParseError> {
             let b = inner_pair.as_str().eq_ignore_ascii_case("true");
             Ok(Value::Boolean(b))
         }
+        Rule::null => Ok(Value::Null),
         _ => Err(ParseError::UnexpectedRuleInValue),
     }
 }
@@ -1,8 +1,16 @@
-use clap::{Parser, Subcommand};
+use clap::{Parser, Subcommand, ValueEnum};
 use std::fs;
-use std::path::PathBuf;
+use std::io;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
 
-use sql_select_parser::{parse_query, ParseError};
+use sql_select_parser::dialect::{
+    AnsiDialect, Dialect, GenericDialect, MySqlDialect, PostgresDialect,
+};
+use sql_select_parser::exec::{execute_query, format_rows, load_table, ExecError};
+use sql_select_parser::{
+    parse_query, parse_query_with_dialect, parse_script_with_dialect, ParseError,
+};
 
 #[derive(Parser)]
 #[command(name = "sql_select_parser")]
@@ -14,12 +22,61 @@ struct Cli {
     command: Commands,
 }
 
+/// SQL dialect selectable on the command line, mapping to a
+/// [`sql_select_parser::dialect::Dialect`] implementation.
+#[derive(Clone, Copy, ValueEnum)]
+enum CliDialect {
+    Generic,
+    Ansi,
+    Mysql,
+    Postgres,
+}
+
+/// How the `Parse` command renders a parsed query.
+#[derive(Clone, Copy, ValueEnum)]
+enum OutputFormat {
+    /// Rust's `{:#?}` Debug form.
+    Debug,
+    /// Machine-readable JSON, via `serde_json`.
+    Json,
+}
+
+impl CliDialect {
+    fn as_dialect(self) -> Box<dyn Dialect> {
+        match self {
+            CliDialect::Generic => Box::new(GenericDialect),
+            CliDialect::Ansi => Box::new(AnsiDialect),
+            CliDialect::Mysql => Box::new(MySqlDialect),
+            CliDialect::Postgres => Box::new(PostgresDialect),
+        }
+    }
+}
+
 #[derive(Subcommand)]
 enum Commands {
     Parse {
         #[arg(short, long, value_name = "FILE")]
         file: PathBuf,
+        #[arg(short, long, value_enum, default_value = "generic")]
+        dialect: CliDialect,
+        /// Parse every `;`-separated statement, reporting each result
+        /// instead of stopping at the first failure.
+        #[arg(long)]
+        continue_on_error: bool,
+        #[arg(long, value_enum, default_value = "debug")]
+        format: OutputFormat,
+    },
+    Format {
+        #[arg(short, long, value_name = "FILE")]
+        file: PathBuf,
     },
+    Query {
+        #[arg(short, long, value_name = "SQL")]
+        sql: String,
+        #[arg(short, long, value_name = "FILE")]
+        from: PathBuf,
+    },
+    Repl,
     Credits,
 }
 
@@ -27,8 +84,36 @@ fn main() {
     let cli = Cli::parse();
 
     match &cli.command {
-        Commands::Parse { file } => {
-            if let Err(e) = handle_parse_command(file) {
+        Commands::Parse {
+            file,
+            dialect,
+            continue_on_error,
+            format,
+        } => {
+            if *continue_on_error {
+                if !handle_parse_script_command(file, *dialect, *format) {
+                    std::process::exit(1);
+                }
+            } else if let Err(e) = handle_parse_command(file, *dialect, *format) {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            }
+        }
+        Commands::Format { file } => {
+            if let Err(e) = handle_format_command(file) {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            }
+        }
+        Commands::Query { sql, from } => {
+            if let Err(e) = handle_query_command(sql, from) {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            }
+        }
+        Commands::Repl => {
+            let stdin = io::stdin();
+            if let Err(e) = sql_select_parser::repl::run(stdin.lock(), io::stdout()) {
                 eprintln!("Error: {}", e);
                 std::process::exit(1);
             }
@@ -39,28 +124,109 @@ fn main() {
     }
 }
 
-fn handle_parse_command(file: &PathBuf) -> Result<(), ParseError> {
-    let content = fs::read_to_string(file).map_err(|e| {
-        ParseError::ParsingError(format!(
-            "Failed to read file {}: {}",
-            file.display(),
-            e
-        ))
-    })?;
+fn handle_parse_command(
+    file: &PathBuf,
+    dialect: CliDialect,
+    format: OutputFormat,
+) -> Result<(), ParseError> {
+    let content = read_query_file(file)?;
+
+    match parse_query_with_dialect(&content, dialect.as_dialect().as_ref()) {
+        Ok(query) => {
+            print_query(&query, format);
+            Ok(())
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// Parses every statement in `file`, printing a per-statement result and
+/// continuing past failures instead of stopping at the first one. Returns
+/// `false` if any statement failed to parse, so the caller can set a
+/// non-zero exit code.
+fn handle_parse_script_command(file: &PathBuf, dialect: CliDialect, format: OutputFormat) -> bool {
+    let content = match read_query_file(file) {
+        Ok(content) => content,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return false;
+        }
+    };
+
+    let mut all_ok = true;
+    for (index, result) in parse_script_with_dialect(&content, dialect.as_dialect().as_ref())
+        .into_iter()
+        .enumerate()
+    {
+        match result {
+            Ok(query) => {
+                println!("Statement {}: OK", index + 1);
+                print_query(&query, format);
+            }
+            Err(e) => {
+                all_ok = false;
+                println!("Statement {}: FAILED\n{}", index + 1, e);
+            }
+        }
+    }
+
+    all_ok
+}
+
+/// Renders a parsed query in the requested [`OutputFormat`].
+fn print_query(query: &sql_select_parser::SelectQuery, format: OutputFormat) {
+    match format {
+        OutputFormat::Debug => println!("{:#?}", query),
+        OutputFormat::Json => match serde_json::to_string_pretty(query) {
+            Ok(json) => println!("{}", json),
+            Err(e) => eprintln!("Error: failed to serialize query as JSON: {}", e),
+        },
+    }
+}
+
+fn handle_format_command(file: &PathBuf) -> Result<(), ParseError> {
+    let content = read_query_file(file)?;
 
     match parse_query(&content) {
         Ok(query) => {
-            println!("Parsed Query:\n{:#?}", query);
+            println!("{}", query.to_sql());
             Ok(())
         }
         Err(e) => Err(e),
     }
 }
 
+/// Errors raised while handling the `query` subcommand, spanning both the
+/// parsing stage and the execution stage.
+#[derive(Debug, Error)]
+enum QueryCommandError {
+    #[error("{0}")]
+    Parse(#[from] ParseError),
+
+    #[error("{0}")]
+    Exec(#[from] ExecError),
+}
+
+fn handle_query_command(sql: &str, from: &Path) -> Result<(), QueryCommandError> {
+    let query = parse_query(sql)?;
+    let rows = load_table(from)?;
+    let results = execute_query(&query, &rows)?;
+
+    println!("{}", format_rows(&query, &results));
+
+    Ok(())
+}
+
+fn read_query_file(file: &PathBuf) -> Result<String, ParseError> {
+    fs::read_to_string(file).map_err(|e| {
+        ParseError::ParsingError(format!("Failed to read file {}: {}", file.display(), e))
+    })
+}
+
 fn handle_credits_command() {
     println!("SQL Parser CLI");
     println!("Version 1.0");
     println!("Developed by Bohdan Prokhorov");
     println!("© 2023 bohdamnnnnn");
     println!("\nThis tool parses SIMPLE SQL SELECT queries and outputs their abstract syntax tree (AST).");
-}
\ No newline at end of file
+}
@@ -0,0 +1,132 @@
+//! An interactive REPL for iteratively parsing and running SELECT queries.
+//!
+//! Lines beginning with `.` are meta-commands (`.help`, `.exit`, `.open`,
+//! `.ast`) handled by [`MetaCommand`] before anything reaches the SQL
+//! parser; every other line is treated as a query and dispatched to
+//! [`parse_query`]. `.open` sets the data file that subsequent queries are
+//! executed against via [`crate::exec`]; `.ast` toggles printing the parsed
+//! AST instead of running the query against the open file.
+
+use std::io::{self, BufRead, Write};
+use std::path::PathBuf;
+
+use crate::exec::{execute_query, format_rows, load_table};
+use crate::parse_query;
+
+/// A parsed REPL input line, classified before it is acted on.
+enum MetaCommand<'a> {
+    Help,
+    Exit,
+    Open(&'a str),
+    Ast,
+    Unknown(&'a str),
+}
+
+impl<'a> MetaCommand<'a> {
+    /// Recognizes a `.`-prefixed meta-command, or returns `None` for a line
+    /// that should be parsed as SQL instead.
+    fn parse(line: &'a str) -> Option<Self> {
+        let rest = line.strip_prefix('.')?;
+        let mut parts = rest.splitn(2, char::is_whitespace);
+        let command = parts.next().unwrap_or_default();
+        let argument = parts.next().unwrap_or_default().trim();
+
+        Some(match command {
+            "help" => MetaCommand::Help,
+            "exit" | "quit" => MetaCommand::Exit,
+            "open" => MetaCommand::Open(argument),
+            "ast" => MetaCommand::Ast,
+            other => MetaCommand::Unknown(other),
+        })
+    }
+}
+
+const HELP_TEXT: &str = "\
+.help         Show this message
+.exit, .quit  Leave the REPL
+.open <file>  Set the CSV/JSON file subsequent queries run against
+.ast          Toggle printing the parsed AST instead of running the query";
+
+/// Runs the REPL, reading lines from `input` until `.exit`/`.quit`/EOF and
+/// writing prompts and results to `output`.
+pub fn run<R: BufRead, W: Write>(mut input: R, mut output: W) -> io::Result<()> {
+    let mut current_file: Option<PathBuf> = None;
+    let mut show_ast = false;
+
+    loop {
+        write!(output, "sql> ")?;
+        output.flush()?;
+
+        let mut line = String::new();
+        if input.read_line(&mut line)? == 0 {
+            break;
+        }
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        match MetaCommand::parse(line) {
+            Some(MetaCommand::Help) => writeln!(output, "{HELP_TEXT}")?,
+            Some(MetaCommand::Exit) => break,
+            Some(MetaCommand::Open(path)) => {
+                if path.is_empty() {
+                    writeln!(output, "Usage: .open <file>")?;
+                } else {
+                    current_file = Some(PathBuf::from(path));
+                    writeln!(output, "Opened {path}")?;
+                }
+            }
+            Some(MetaCommand::Ast) => {
+                show_ast = !show_ast;
+                writeln!(
+                    output,
+                    "AST display: {}",
+                    if show_ast { "on" } else { "off" }
+                )?;
+            }
+            Some(MetaCommand::Unknown(name)) => {
+                writeln!(output, "Unknown meta-command: .{name} (try .help)")?;
+            }
+            None => handle_query_line(line, current_file.as_deref(), show_ast, &mut output)?,
+        }
+    }
+
+    Ok(())
+}
+
+/// Parses `line` as SQL and either prints its AST, runs it against
+/// `current_file`, or reports the absence of an open file, writing the
+/// outcome to `output`.
+fn handle_query_line<W: Write>(
+    line: &str,
+    current_file: Option<&std::path::Path>,
+    show_ast: bool,
+    output: &mut W,
+) -> io::Result<()> {
+    let query = match parse_query(line) {
+        Ok(query) => query,
+        Err(e) => return writeln!(output, "Error: {e}"),
+    };
+
+    if show_ast {
+        return writeln!(output, "{query:#?}");
+    }
+
+    let Some(path) = current_file else {
+        return writeln!(
+            output,
+            "No file open; use .open <file> before running a query"
+        );
+    };
+
+    let rows = match load_table(path) {
+        Ok(rows) => rows,
+        Err(e) => return writeln!(output, "Error: {e}"),
+    };
+
+    match execute_query(&query, &rows) {
+        Ok(results) => writeln!(output, "{}", format_rows(&query, &results)),
+        Err(e) => writeln!(output, "Error: {e}"),
+    }
+}
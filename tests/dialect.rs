@@ -0,0 +1,63 @@
+use anyhow::{Context, Result};
+use sql_select_parser::dialect::{AnsiDialect, GenericDialect, MySqlDialect, PostgresDialect};
+use sql_select_parser::{parse_query_with_dialect, ParseError, Table};
+
+#[test]
+fn test_mysql_dialect_accepts_backtick_identifiers() -> Result<()> {
+    let query = parse_query_with_dialect("select `name` from `orders`", &MySqlDialect)
+        .context("Failed to parse backtick-quoted identifiers under MySqlDialect")?;
+
+    match query.table {
+        Table::Simple { ref name, .. } => assert_eq!(name, "orders"),
+        _ => panic!("Expected simple table 'orders'"),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_generic_dialect_rejects_backtick_identifiers() {
+    let result = parse_query_with_dialect("select `name` from `orders`", &GenericDialect);
+    assert!(matches!(result, Err(ParseError::SyntaxError { .. })));
+}
+
+#[test]
+fn test_ansi_and_postgres_dialects_accept_double_quoted_identifiers() -> Result<()> {
+    for dialect in [&AnsiDialect as &dyn sql_select_parser::dialect::Dialect, &PostgresDialect] {
+        let query = parse_query_with_dialect(r#"select "name" from "orders""#, dialect)
+            .context("Failed to parse double-quoted identifiers")?;
+        match query.table {
+            Table::Simple { ref name, .. } => assert_eq!(name, "orders"),
+            _ => panic!("Expected simple table 'orders'"),
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_postgres_dialect_rejects_its_extra_reserved_words() {
+    let result = parse_query_with_dialect("select ilike from users", &PostgresDialect);
+    assert!(matches!(result, Err(ParseError::ReservedWord(ref w)) if w.eq_ignore_ascii_case("ilike")));
+}
+
+#[test]
+fn test_generic_dialect_allows_postgres_reserved_words() -> Result<()> {
+    parse_query_with_dialect("select ilike from users", &GenericDialect)
+        .context("GenericDialect should not reserve Postgres-specific words")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_quote_inside_string_literal_is_not_treated_as_identifier_delimiter() -> Result<()> {
+    let query = parse_query_with_dialect(
+        r#"select name from users where bio = 'say "hi"'"#,
+        &PostgresDialect,
+    )
+    .context("Failed to parse a string literal containing a dialect quote char")?;
+
+    assert!(query.where_clause.is_some());
+
+    Ok(())
+}
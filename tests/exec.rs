@@ -0,0 +1,92 @@
+use anyhow::{Context, Result};
+use sql_select_parser::exec::{execute_query, format_rows, load_table};
+use sql_select_parser::parse_query;
+
+/// Writes `content` to a uniquely-named file under the OS temp dir with the
+/// given extension, returning its path, so `load_table` can dispatch on the
+/// extension the same way it would for a real data file.
+fn write_fixture(name: &str, extension: &str, content: &str) -> std::path::PathBuf {
+    let path = std::env::temp_dir().join(format!("sql_select_parser_{name}.{extension}"));
+    std::fs::write(&path, content).expect("failed to write fixture file");
+    path
+}
+
+#[test]
+fn test_query_csv_file() -> Result<()> {
+    let path = write_fixture(
+        "exec_csv",
+        "csv",
+        "id,name,price\n1,widget,9.99\n2,gadget,19.99\n3,gizmo,29.99\n",
+    );
+
+    let query = parse_query("select name, price from products where price > 15")
+        .context("Failed to parse query")?;
+    let rows = load_table(&path).context("Failed to load CSV fixture")?;
+    let results = execute_query(&query, &rows).context("Failed to execute query")?;
+
+    assert_eq!(results.len(), 2);
+    assert_eq!(
+        format_rows(&query, &results),
+        "name | price\ngadget | 19.99\ngizmo | 29.99\n(2 rows)"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_query_jsonl_file() -> Result<()> {
+    let path = write_fixture(
+        "exec_jsonl",
+        "jsonl",
+        "{\"id\": 1, \"name\": \"alice\", \"active\": true}\n\
+         {\"id\": 2, \"name\": \"bob\", \"active\": false}\n",
+    );
+
+    let query =
+        parse_query("select * from users where active = true").context("Failed to parse query")?;
+    let rows = load_table(&path).context("Failed to load JSONL fixture")?;
+    let results = execute_query(&query, &rows).context("Failed to execute query")?;
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].get("name").unwrap().to_string(), "'alice'");
+
+    Ok(())
+}
+
+#[test]
+fn test_query_or_condition_with_projection() -> Result<()> {
+    let path = write_fixture(
+        "exec_and_or",
+        "csv",
+        "id,stock,discontinued\n1,40,false\n2,60,false\n3,10,true\n",
+    );
+
+    let query = parse_query(
+        "select id from products where stock < 50 or discontinued = true",
+    )
+    .context("Failed to parse query")?;
+    let rows = load_table(&path).context("Failed to load CSV fixture")?;
+    let results = execute_query(&query, &rows).context("Failed to execute query")?;
+
+    let ids: Vec<String> = results
+        .iter()
+        .map(|row| row.get("id").unwrap().to_string())
+        .collect();
+    assert_eq!(ids, vec!["1", "3"]);
+
+    Ok(())
+}
+
+#[test]
+fn test_format_rows_prints_header_for_empty_result() -> Result<()> {
+    let path = write_fixture("exec_empty", "csv", "id,name\n1,a\n");
+    let query = parse_query("select name from items where id > 100")
+        .context("Failed to parse query")?;
+    let rows = load_table(&path).context("Failed to load CSV fixture")?;
+    let results = execute_query(&query, &rows).context("Failed to execute query")?;
+
+    assert!(results.is_empty());
+    assert_eq!(format_rows(&query, &results), "name\n(0 rows)");
+
+    Ok(())
+}
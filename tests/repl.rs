@@ -0,0 +1,68 @@
+use std::io::Cursor;
+
+use sql_select_parser::repl;
+
+/// Runs `repl::run` over `input` (joined with newlines) and returns
+/// everything written to `output`, so assertions can grep the transcript
+/// instead of having to replicate the REPL's prompt/response framing.
+fn run_repl(input: &[&str]) -> String {
+    let mut joined = input.join("\n");
+    joined.push('\n');
+    let mut output = Vec::new();
+    repl::run(Cursor::new(joined.into_bytes()), &mut output).expect("repl::run should not error");
+    String::from_utf8(output).expect("repl output should be valid UTF-8")
+}
+
+#[test]
+fn test_help_and_exit() {
+    let transcript = run_repl(&[".help", ".exit"]);
+
+    assert!(transcript.contains(".open <file>"));
+    assert!(transcript.contains("Toggle printing the parsed AST"));
+}
+
+#[test]
+fn test_unknown_meta_command() {
+    let transcript = run_repl(&[".bogus", ".exit"]);
+
+    assert!(transcript.contains("Unknown meta-command: .bogus"));
+}
+
+#[test]
+fn test_query_without_open_file_reports_no_file() {
+    let transcript = run_repl(&["select id from users", ".exit"]);
+
+    assert!(transcript.contains("No file open; use .open <file> before running a query"));
+}
+
+#[test]
+fn test_ast_toggle_prints_debug_ast_instead_of_running_query() {
+    let transcript = run_repl(&[".ast", "select id from users", ".exit"]);
+
+    assert!(transcript.contains("AST display: on"));
+    assert!(transcript.contains("SelectQuery"));
+    assert!(transcript.contains("Simple"));
+}
+
+#[test]
+fn test_open_and_query_against_a_real_file() {
+    let path = std::env::temp_dir().join("sql_select_parser_repl.csv");
+    std::fs::write(&path, "id,name\n1,alice\n2,bob\n").expect("failed to write fixture file");
+
+    let transcript = run_repl(&[
+        &format!(".open {}", path.display()),
+        "select name from users where id = 2",
+        ".exit",
+    ]);
+
+    assert!(transcript.contains(&format!("Opened {}", path.display())));
+    assert!(transcript.contains("bob"));
+    assert!(transcript.contains("(1 row)"));
+}
+
+#[test]
+fn test_parse_error_is_reported_without_crashing() {
+    let transcript = run_repl(&["select from", ".exit"]);
+
+    assert!(transcript.contains("Error:"));
+}
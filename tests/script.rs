@@ -0,0 +1,100 @@
+use sql_select_parser::{parse_script, ParseError, Table};
+
+#[test]
+fn test_parse_script_splits_on_semicolons() {
+    let script = "select id from users; select name from orders;";
+
+    let results = parse_script(script);
+
+    assert_eq!(results.len(), 2);
+    match &results[0] {
+        Ok(query) => match query.table {
+            Table::Simple { ref name, .. } => assert_eq!(name, "users"),
+            _ => panic!("Expected simple table 'users'"),
+        },
+        Err(e) => panic!("first statement should parse: {e}"),
+    }
+    match &results[1] {
+        Ok(query) => match query.table {
+            Table::Simple { ref name, .. } => assert_eq!(name, "orders"),
+            _ => panic!("Expected simple table 'orders'"),
+        },
+        Err(e) => panic!("second statement should parse: {e}"),
+    }
+}
+
+#[test]
+fn test_parse_script_ignores_semicolons_inside_string_literals() {
+    let script = "select name from users where bio = 'a; b';";
+
+    let results = parse_script(script);
+
+    assert_eq!(results.len(), 1);
+    if let Err(e) = &results[0] {
+        panic!("statement with a semicolon in a string literal should parse as one statement: {e}");
+    }
+}
+
+#[test]
+fn test_parse_script_recovers_past_a_bad_statement() {
+    let script = "select id from users;\nselect from;\nselect name from orders;";
+
+    let results = parse_script(script);
+
+    assert_eq!(results.len(), 3);
+    assert!(results[0].is_ok());
+    assert!(results[1].is_err());
+    assert!(results[2].is_ok());
+}
+
+#[test]
+fn test_parse_script_remaps_error_line_to_the_whole_script() {
+    let script = "select id from users;\nselect name from orders;\nselect from;";
+
+    let results = parse_script(script);
+
+    assert_eq!(results.len(), 3);
+    match results[2] {
+        Err(ParseError::SyntaxError { line, .. }) => assert_eq!(line, 3),
+        ref other => panic!("Expected a SyntaxError on line 3, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_parse_script_remaps_error_column_for_an_indented_statement() {
+    // The bad statement's own "from" sits at column 12 of its line in the
+    // whole script, not at column 8, where it would fall if it were parsed
+    // in isolation after being trimmed of its leading indentation.
+    let script = "select id from users;\n    select from;\nselect name from orders;";
+
+    let results = parse_script(script);
+
+    assert_eq!(results.len(), 3);
+    match &results[1] {
+        Err(ParseError::SyntaxError {
+            line,
+            column,
+            line_text,
+            ..
+        }) => {
+            assert_eq!(*line, 2);
+            assert_eq!(*column, 12);
+            assert_eq!(line_text, "    select from;");
+        }
+        other => panic!("Expected a SyntaxError on line 2, column 12, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_parse_script_skips_empty_statements() {
+    let script = "select id from users;;  ;\nselect name from orders;";
+
+    let results = parse_script(script);
+
+    assert_eq!(results.len(), 2);
+    for result in &results {
+        if let Err(e) = result {
+            panic!("non-empty statements should parse: {e}");
+        }
+    }
+}
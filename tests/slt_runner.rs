@@ -0,0 +1,134 @@
+//! A small SqlLogicTest-style runner: reads `.slt` golden files under
+//! `tests/slt/`, each holding `statement ok`/`statement error` records, and
+//! checks every one against `parse_query` before failing. Mismatches are
+//! collected and reported together instead of panicking on the first one,
+//! so a single `cargo test` run shows the full extent of a grammar
+//! regression across the corpus.
+
+use std::fs;
+use std::path::Path;
+
+use sql_select_parser::parse_query;
+
+/// One `statement ok`/`statement error` record parsed out of a `.slt` file.
+struct Record {
+    /// 1-based line number of the directive, for failure messages.
+    line: usize,
+    /// `true` for `statement ok`, `false` for `statement error`.
+    expect_ok: bool,
+    /// The SQL text to feed to `parse_query`.
+    sql: String,
+    /// For `statement ok`, the expected `to_sql()` rendering, if a `----`
+    /// block was given. `statement error` records never have one.
+    expected_rendering: Option<String>,
+}
+
+/// Splits a `.slt` file's content into [`Record`]s. Records are separated
+/// by blank lines; `#`-prefixed lines are comments and are skipped.
+fn parse_records(content: &str) -> Vec<Record> {
+    let mut records = Vec::new();
+    let mut lines = content.lines().enumerate().peekable();
+
+    while let Some((index, line)) = lines.next() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+
+        let expect_ok = match trimmed {
+            "statement ok" => true,
+            "statement error" => false,
+            other => panic!("line {}: unrecognized directive `{other}`", index + 1),
+        };
+
+        let mut sql_lines = Vec::new();
+        let mut expected_rendering = None;
+
+        while let Some((_, next_line)) = lines.peek() {
+            if next_line.trim().is_empty() {
+                break;
+            }
+            let (_, next_line) = lines.next().unwrap();
+            if next_line.trim() == "----" {
+                let mut expected_lines = Vec::new();
+                while let Some((_, expected_line)) = lines.peek() {
+                    if expected_line.trim().is_empty() {
+                        break;
+                    }
+                    expected_lines.push(lines.next().unwrap().1);
+                }
+                expected_rendering = Some(expected_lines.join("\n"));
+                break;
+            }
+            sql_lines.push(next_line);
+        }
+
+        records.push(Record {
+            line: index + 1,
+            expect_ok,
+            sql: sql_lines.join("\n"),
+            expected_rendering,
+        });
+    }
+
+    records
+}
+
+/// Runs every record in `path` against `parse_query`, appending a
+/// description of each mismatch to `failures`.
+fn check_file(path: &Path, failures: &mut Vec<String>) {
+    let content = fs::read_to_string(path).expect("failed to read .slt file");
+    let file = path.display();
+
+    for record in parse_records(&content) {
+        match (parse_query(&record.sql), record.expect_ok) {
+            (Ok(query), true) => {
+                if let Some(expected) = &record.expected_rendering {
+                    let rendered = query.to_sql();
+                    if &rendered != expected {
+                        failures.push(format!(
+                            "{file}:{}: expected rendering `{expected}`, got `{rendered}`",
+                            record.line
+                        ));
+                    }
+                }
+            }
+            (Ok(_), false) => {
+                failures.push(format!(
+                    "{file}:{}: expected `{}` to fail parsing, but it succeeded",
+                    record.line, record.sql
+                ));
+            }
+            (Err(e), true) => {
+                failures.push(format!(
+                    "{file}:{}: expected `{}` to parse, but it failed: {e}",
+                    record.line, record.sql
+                ));
+            }
+            (Err(_), false) => {}
+        }
+    }
+}
+
+#[test]
+fn slt_corpus() {
+    let corpus_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/slt");
+    let mut entries: Vec<_> = fs::read_dir(&corpus_dir)
+        .expect("failed to read tests/slt directory")
+        .map(|entry| entry.expect("failed to read directory entry").path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "slt"))
+        .collect();
+    entries.sort();
+
+    let mut failures = Vec::new();
+    for path in &entries {
+        check_file(path, &mut failures);
+    }
+
+    assert!(
+        failures.is_empty(),
+        "{} mismatch(es) in the .slt corpus:\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}
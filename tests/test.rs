@@ -1,6 +1,6 @@
 use anyhow::{Context, Result};
 use sql_select_parser::parse_query;
-use sql_select_parser::{SelectItem, Table, Value};
+use sql_select_parser::{ParseError, SelectItem, Table, Value, ValueType, WhereExpr};
 
 #[test]
 fn test_whitespace_handling() -> Result<()> {
@@ -10,21 +10,21 @@ fn test_whitespace_handling() -> Result<()> {
 
     assert_eq!(parsed.columns.len(), 2);
     match &parsed.columns[0] {
-        SelectItem::Column(col) => assert_eq!(col, "name"),
+        SelectItem::Column { name, .. } => assert_eq!(name, "name"),
         _ => panic!("Expected column 'name'"),
     }
     match &parsed.columns[1] {
-        SelectItem::Column(col) => assert_eq!(col, "age"),
+        SelectItem::Column { name, .. } => assert_eq!(name, "age"),
         _ => panic!("Expected column 'age'"),
     }
 
     match parsed.table {
-        Table::Simple(ref table_name) => assert_eq!(table_name, "users"),
+        Table::Simple { ref name, .. } => assert_eq!(name, "users"),
         _ => panic!("Expected simple table 'users'"),
     }
 
     match parsed.where_clause {
-        Some(ref condition) => {
+        Some(WhereExpr::Condition(ref condition)) => {
             assert_eq!(condition.left, "active");
             assert_eq!(condition.operator, "=");
             match condition.right {
@@ -32,7 +32,7 @@ fn test_whitespace_handling() -> Result<()> {
                 _ => panic!("Expected boolean value in WHERE clause"),
             }
         }
-        None => panic!("Expected where clause"),
+        _ => panic!("Expected a single WHERE condition"),
     }
 
     Ok(())
@@ -46,7 +46,7 @@ fn test_select_keyword() -> Result<()> {
 
     assert_eq!(parsed.columns.len(), 1);
     match &parsed.columns[0] {
-        SelectItem::Column(col) => assert_eq!(col, "name"),
+        SelectItem::Column { name, .. } => assert_eq!(name, "name"),
         _ => panic!("Expected column 'name'"),
     }
 
@@ -60,7 +60,7 @@ fn test_from_keyword() -> Result<()> {
     let parsed = parse_query(query).context("Failed to parse query with from keyword")?;
 
     match parsed.table {
-        Table::Simple(ref table_name) => assert_eq!(table_name, "users"),
+        Table::Simple { ref name, .. } => assert_eq!(name, "users"),
         _ => panic!("Expected simple table 'users'"),
     }
 
@@ -86,16 +86,16 @@ fn test_identifier() -> Result<()> {
 
     assert_eq!(parsed.columns.len(), 2);
     match &parsed.columns[0] {
-        SelectItem::Column(col) => assert_eq!(col, "user_name"),
+        SelectItem::Column { name, .. } => assert_eq!(name, "user_name"),
         _ => panic!("Expected column 'user_name'"),
     }
     match &parsed.columns[1] {
-        SelectItem::Column(col) => assert_eq!(col, "_email"),
+        SelectItem::Column { name, .. } => assert_eq!(name, "_email"),
         _ => panic!("Expected column '_email'"),
     }
 
     match parsed.table {
-        Table::Simple(ref table_name) => assert_eq!(table_name, "users_table"),
+        Table::Simple { ref name, .. } => assert_eq!(name, "users_table"),
         _ => panic!("Expected simple table 'users_table'"),
     }
 
@@ -109,7 +109,7 @@ fn test_number_value() -> Result<()> {
     let parsed = parse_query(query).context("Failed to parse query with numeric value")?;
 
     match parsed.where_clause {
-        Some(ref condition) => {
+        Some(WhereExpr::Condition(ref condition)) => {
             assert_eq!(condition.left, "quantity");
             assert_eq!(condition.operator, "=");
             match condition.right {
@@ -117,7 +117,62 @@ fn test_number_value() -> Result<()> {
                 _ => panic!("Expected number value in where clause"),
             }
         }
-        None => panic!("Expected WHERE clause"),
+        _ => panic!("Expected a single WHERE condition"),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_where_clause_and_or_not() -> Result<()> {
+    let query = "select id from products where price > 100 and (stock < 50 or discontinued = false)";
+
+    let parsed = parse_query(query).context("Failed to parse nested AND/OR WHERE clause")?;
+
+    match parsed.where_clause {
+        Some(WhereExpr::And(ref left, ref right)) => {
+            match **left {
+                WhereExpr::Condition(ref condition) => {
+                    assert_eq!(condition.left, "price");
+                    assert_eq!(condition.operator, ">");
+                }
+                _ => panic!("Expected 'price > 100' on the left of AND"),
+            }
+
+            match **right {
+                WhereExpr::Or(ref or_left, ref or_right) => {
+                    match **or_left {
+                        WhereExpr::Condition(ref condition) => assert_eq!(condition.left, "stock"),
+                        _ => panic!("Expected 'stock < 50' on the left of OR"),
+                    }
+                    match **or_right {
+                        WhereExpr::Condition(ref condition) => {
+                            assert_eq!(condition.left, "discontinued")
+                        }
+                        _ => panic!("Expected 'discontinued = false' on the right of OR"),
+                    }
+                }
+                _ => panic!("Expected parenthesized OR on the right of AND"),
+            }
+        }
+        _ => panic!("Expected a top-level AND"),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_where_clause_not() -> Result<()> {
+    let query = "select id from products where not discontinued = true";
+
+    let parsed = parse_query(query).context("Failed to parse NOT in WHERE clause")?;
+
+    match parsed.where_clause {
+        Some(WhereExpr::Not(ref inner)) => match **inner {
+            WhereExpr::Condition(ref condition) => assert_eq!(condition.left, "discontinued"),
+            _ => panic!("Expected a condition inside NOT"),
+        },
+        _ => panic!("Expected a top-level NOT"),
     }
 
     Ok(())
@@ -131,11 +186,13 @@ fn test_function_name() -> Result<()> {
 
     assert_eq!(parsed.columns.len(), 1);
     match &parsed.columns[0] {
-        SelectItem::Function { name, arguments } => {
+        SelectItem::Function {
+            name, arguments, ..
+        } => {
             assert_eq!(name, "count");
             assert_eq!(arguments.len(), 1);
             match &arguments[0] {
-                SelectItem::Column(col) => assert_eq!(col, "id"),
+                SelectItem::Column { name, .. } => assert_eq!(name, "id"),
                 _ => panic!("Expected column 'id' as function argument"),
             }
         }
@@ -153,12 +210,12 @@ fn test_select_star() -> Result<()> {
 
     assert_eq!(parsed.columns.len(), 1);
     match &parsed.columns[0] {
-        SelectItem::Column(col) => assert_eq!(col, "*"),
+        SelectItem::Column { name, .. } => assert_eq!(name, "*"),
         _ => panic!("Expected '*' in select columns"),
     }
 
     match parsed.table {
-        Table::Simple(ref table_name) => assert_eq!(table_name, "users"),
+        Table::Simple { ref name, .. } => assert_eq!(name, "users"),
         _ => panic!("Expected simple table 'users'"),
     }
 
@@ -175,7 +232,7 @@ fn test_select_list() -> Result<()> {
     let expected_columns = vec!["id", "name", "email"];
     for (i, col) in expected_columns.iter().enumerate() {
         match &parsed.columns[i] {
-            SelectItem::Column(c) => assert_eq!(c, col),
+            SelectItem::Column { name, .. } => assert_eq!(name, col),
             _ => panic!("Expected column '{}'", col),
         }
     }
@@ -183,6 +240,155 @@ fn test_select_list() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_limit_and_offset() -> Result<()> {
+    let query = "select id from products where price > 100 limit 10 offset 20";
+
+    let parsed = parse_query(query).context("Failed to parse query with LIMIT/OFFSET")?;
+
+    assert_eq!(parsed.limit, Some(10));
+    assert_eq!(parsed.offset, Some(20));
+
+    Ok(())
+}
+
+#[test]
+fn test_limit_without_offset() -> Result<()> {
+    let query = "select id from products limit 5";
+
+    let parsed = parse_query(query).context("Failed to parse query with LIMIT only")?;
+
+    assert_eq!(parsed.limit, Some(5));
+    assert!(parsed.offset.is_none());
+
+    Ok(())
+}
+
+#[test]
+fn test_negative_limit_is_rejected() -> Result<()> {
+    let query = "select id from products limit -5";
+
+    let parsed = parse_query(query);
+
+    assert!(parsed.is_err(), "Expected negative LIMIT to be rejected");
+
+    Ok(())
+}
+
+#[test]
+fn test_where_in_list() -> Result<()> {
+    let query = "select name from users where role in ('admin', 'mod')";
+
+    let parsed = parse_query(query).context("Failed to parse IN list in WHERE clause")?;
+
+    match parsed.where_clause {
+        Some(WhereExpr::Condition(ref condition)) => {
+            assert_eq!(condition.left, "role");
+            assert!(condition.operator.eq_ignore_ascii_case("in"));
+            match &condition.right {
+                Value::List(values) => {
+                    assert_eq!(values.len(), 2);
+                    match &values[0] {
+                        Value::String(s) => assert_eq!(s, "admin"),
+                        _ => panic!("Expected string value in IN list"),
+                    }
+                }
+                _ => panic!("Expected a value list for IN"),
+            }
+        }
+        _ => panic!("Expected a single WHERE condition"),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_where_like() -> Result<()> {
+    let query = "select name from users where name like '%smith%'";
+
+    let parsed = parse_query(query).context("Failed to parse LIKE in WHERE clause")?;
+
+    match parsed.where_clause {
+        Some(WhereExpr::Condition(ref condition)) => {
+            assert!(condition.operator.eq_ignore_ascii_case("like"));
+            match &condition.right {
+                Value::String(s) => assert_eq!(s, "%smith%"),
+                _ => panic!("Expected string value for LIKE"),
+            }
+        }
+        _ => panic!("Expected a single WHERE condition"),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_in_with_bare_scalar_is_rejected() -> Result<()> {
+    let query = "select name from users where role in 'admin'";
+
+    let parsed = parse_query(query);
+
+    assert!(
+        parsed.is_err(),
+        "Expected IN with a bare scalar to be rejected"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_where_float_value() -> Result<()> {
+    let query = "select name from products where price > 99.95";
+
+    let parsed = parse_query(query).context("Failed to parse float value in WHERE clause")?;
+
+    match parsed.where_clause {
+        Some(WhereExpr::Condition(ref condition)) => match condition.right {
+            Value::Float(f) => {
+                assert!((f - 99.95).abs() < f64::EPSILON);
+                assert_eq!(condition.right.value_type(), ValueType::Float);
+            }
+            _ => panic!("Expected float value in WHERE clause"),
+        },
+        _ => panic!("Expected a single WHERE condition"),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_where_null_value() -> Result<()> {
+    let query = "select name from products where deleted_at = NULL";
+
+    let parsed = parse_query(query).context("Failed to parse NULL value in WHERE clause")?;
+
+    match parsed.where_clause {
+        Some(WhereExpr::Condition(ref condition)) => {
+            assert_eq!(condition.right.value_type(), ValueType::Null);
+        }
+        _ => panic!("Expected a single WHERE condition"),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_where_is_null() -> Result<()> {
+    let query = "select name from products where deleted_at IS NULL";
+
+    let parsed = parse_query(query).context("Failed to parse IS NULL in WHERE clause")?;
+
+    match parsed.where_clause {
+        Some(WhereExpr::Condition(ref condition)) => {
+            assert!(condition.operator.eq_ignore_ascii_case("is"));
+            assert!(matches!(condition.right, Value::Null));
+        }
+        _ => panic!("Expected a single WHERE condition"),
+    }
+
+    Ok(())
+}
+
 #[test]
 fn test_table_subquery() -> Result<()> {
     let query = "select name from (select name, age from users)b";
@@ -191,28 +397,64 @@ fn test_table_subquery() -> Result<()> {
 
     assert_eq!(parsed.columns.len(), 1);
     match &parsed.columns[0] {
-        SelectItem::Column(ref col) => assert_eq!(col, "name"),
+        SelectItem::Column { ref name, .. } => assert_eq!(name, "name"),
         _ => panic!("Expected column 'name'"),
     }
     match parsed.table {
-        Table::Subquery(ref subquery) => {
-            assert_eq!(subquery.columns.len(), 2);
-            match &subquery.columns[0] {
-                SelectItem::Column(col) => assert_eq!(col, "name"),
+        Table::Subquery {
+            ref query,
+            ref alias,
+        } => {
+            assert_eq!(query.columns.len(), 2);
+            match &query.columns[0] {
+                SelectItem::Column { name, .. } => assert_eq!(name, "name"),
                 _ => panic!("Expected column 'name' in subquery"),
             }
-            match &subquery.columns[1] {
-                SelectItem::Column(col) => assert_eq!(col, "age"),
+            match &query.columns[1] {
+                SelectItem::Column { name, .. } => assert_eq!(name, "age"),
                 _ => panic!("Expected column 'age' in subquery"),
             }
 
-            match subquery.table {
-                Table::Simple(ref table_name) => assert_eq!(table_name, "users"),
+            match query.table {
+                Table::Simple { ref name, .. } => assert_eq!(name, "users"),
                 _ => panic!("Expected simple table 'users' in subquery"),
             }
 
-            assert!(subquery.where_clause.is_none());
+            assert!(query.where_clause.is_none());
+            assert_eq!(alias.as_deref(), Some("b"));
+        }
+        _ => panic!("Expected table as subquery"),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_select_item_alias() -> Result<()> {
+    let query = "select count(id) as total from orders";
+
+    let parsed = parse_query(query).context("Failed to parse select item with alias")?;
+
+    assert_eq!(parsed.columns.len(), 1);
+    match &parsed.columns[0] {
+        SelectItem::Function { name, alias, .. } => {
+            assert_eq!(name, "count");
+            assert_eq!(alias.as_deref(), Some("total"));
         }
+        _ => panic!("Expected function with alias"),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_table_alias_with_as() -> Result<()> {
+    let query = "select total from (select count(id) as total from orders) as sub";
+
+    let parsed = parse_query(query).context("Failed to parse subquery with AS alias")?;
+
+    match parsed.table {
+        Table::Subquery { ref alias, .. } => assert_eq!(alias.as_deref(), Some("sub")),
         _ => panic!("Expected table as subquery"),
     }
 
@@ -236,3 +478,54 @@ fn test_invalid_select_missing_columns() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_syntax_error_reports_line_column_and_expected() -> Result<()> {
+    let query = "select name users";
+
+    let err = parse_query(query).expect_err("Expected parsing to fail due to missing FROM");
+
+    match err {
+        ParseError::SyntaxError {
+            line,
+            column,
+            expected,
+            ..
+        } => {
+            assert_eq!(line, 1);
+            assert!(column > 1);
+            assert!(!expected.is_empty());
+        }
+        other => panic!("Expected a SyntaxError, got {other:?}"),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_to_sql_normalizes_casing_and_whitespace() -> Result<()> {
+    let query = "select   name,  age from   users where age > 20 and active = true limit 10";
+
+    let parsed = parse_query(query).context("Failed to parse query")?;
+
+    assert_eq!(
+        parsed.to_sql(),
+        "SELECT name, age FROM users WHERE age > 20 AND active = TRUE LIMIT 10"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_to_sql_adds_parens_to_preserve_precedence() -> Result<()> {
+    let query = "select name from users where (age > 20 or age < 5) and active = true";
+
+    let parsed = parse_query(query).context("Failed to parse query")?;
+
+    assert_eq!(
+        parsed.to_sql(),
+        "SELECT name FROM users WHERE (age > 20 OR age < 5) AND active = TRUE"
+    );
+
+    Ok(())
+}